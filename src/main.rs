@@ -5,7 +5,7 @@ use ratatui::{
     backend::CrosstermBackend,
     buffer::Buffer,
     crossterm::{
-        event::{self, Event, KeyCode},
+        event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
         ExecutableCommand,
         terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
     },
@@ -16,9 +16,13 @@ use solitaire_base::index::{
     Location as SolitaireLocation,
 };
 
+mod save;
+mod solver;
+
 #[derive(Clone)]
 struct Board {
     board: solitaire_base::Board,
+    seed: u64,
 }
 
 fn key_to_slot(key: KeyCode) -> Result<SolitaireSlot, ()> {
@@ -43,13 +47,53 @@ enum BoardState {
     CollectDragon,
     SemiPickup(u8),
     Pickup(SolitaireLocation),
+    /// Overlay highlighting a suggested move: the `depth` cards at the bottom
+    /// of `from` shown as a source and the `to` slot as a target. It stays on
+    /// screen until the next keypress, which dismisses it back to `View`.
+    Hint { from: SolitaireSlot, depth: u8, to: SolitaireSlot },
 }
 
 impl Board {
-    fn new() -> Self {
-        Self {
-            board: solitaire_base::Board::new_random(),
+    /// Deal the layout identified by `seed`. The shuffle is driven by a `rand`
+    /// RNG seeded from `seed` and owned here, so the same seed always produces
+    /// the same 40-card arrangement regardless of the base crate's own dealer —
+    /// this is what lets deals be retried or shared by number.
+    fn from_seed(seed: u64) -> Self {
+        use rand::SeedableRng;
+        use rand::seq::SliceRandom;
+
+        // Take the full 40-card deck out of an arbitrary deal, then re-deal it
+        // ourselves under the seeded RNG using only the public slot API.
+        let mut board = solitaire_base::Board::new_random();
+        let mut deck = Vec::with_capacity(40);
+        for slot in solitaire_base::index::ALL_SLOTS.iter() {
+            while let Some(card) = board.pop(*slot) {
+                deck.push(card);
+            }
         }
+        // The arbitrary deal leaves the deck in a varying order; sort it into a
+        // fixed one so the seeded shuffle always starts from the same sequence.
+        deck.sort_by_key(|card| match card {
+            solitaire_base::card::Card::Number(suit, rank) => (0u8, *suit as u8, *rank),
+            solitaire_base::card::Card::Dragon(color) => (1u8, *color as u8, 0u8),
+            solitaire_base::card::Card::Flower => (2u8, 0u8, 0u8),
+        });
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        deck.shuffle(&mut rng);
+
+        // Standard opening layout: eight tray columns of five cards each.
+        for (i, card) in deck.into_iter().enumerate() {
+            board.push(SolitaireSlot::Tray((i / 5) as u8), card);
+        }
+        board.simplify();
+
+        Self { board, seed }
+    }
+
+    /// Deal a fresh layout from a randomly chosen seed.
+    fn random() -> Self {
+        Self::from_seed(rand::random())
     }
 }
 
@@ -98,6 +142,8 @@ impl StatefulWidget for Board {
                             match state {
                                 BoardState::Pickup(SolitaireLocation::Spare(n)) if *n == i as u8 => card_style_selected,
                                 BoardState::CollectDragon => card_style_semi_selected,
+                                BoardState::Hint { to: SolitaireSlot::Spare(n), .. } if *n == i as u8 => card_style_selected,
+                                BoardState::Hint { from: SolitaireSlot::Spare(n), .. } if *n == i as u8 => card_style_semi_selected,
                                 _ => if let Some(card) = self.board.get(SolitaireSlot::Spare(i as u8)).next() { card_style_normal.colorize(card) } else { card_style_normal },
                             });
         });
@@ -119,14 +165,27 @@ impl StatefulWidget for Board {
                                if let Some(card) = card { match state {
                                    BoardState::SemiPickup(n) if *n == j => { card_style_semi_selected }
                                    BoardState::Pickup(SolitaireLocation::Tray(n, m)) if *n == j && i + 2 > *m as usize => { card_style_selected }
+                                   BoardState::Hint { to: SolitaireSlot::Tray(n), .. } if *n == j => { card_style_selected }
+                                   BoardState::Hint { from: SolitaireSlot::Tray(n), depth, .. } if *n == j && i + (*depth as usize) >= self.board.get(SolitaireSlot::Tray(j)).count() => { card_style_semi_selected }
                                    _ => card_style_normal.colorize(card)
-                               }} else { card_style_normal })
+                               }} else {
+                                   // An empty destination column has no card to
+                                   // tint, so mark its top cell as the target.
+                                   match state {
+                                       BoardState::Hint { to: SolitaireSlot::Tray(n), .. } if *n == j && i == 0 => card_style_selected,
+                                       _ => card_style_normal,
+                                   }
+                               })
             }
         }
     }
 }
 
-fn change_board_state(board: &mut Board, state: &mut BoardState, info: &mut Option<String>, key: KeyCode) {
+/// Apply a key press to the interaction state machine. Returns `true` when the
+/// key caused a successful mutation of the `Board` (a card move, dragon
+/// collection, or the flower/output auto-moves inside `simplify`), so the
+/// caller can snapshot the new position into the undo [`History`].
+fn change_board_state(board: &mut Board, state: &mut BoardState, info: &mut Option<String>, key: KeyCode) -> bool {
     if info.is_some() { *info = None; }
     match state {
         BoardState::View => {
@@ -145,21 +204,25 @@ fn change_board_state(board: &mut Board, state: &mut BoardState, info: &mut Opti
                 KeyCode::Char('d') => *state = BoardState::CollectDragon,
                 _ => {}
             }
+            false
         }
         BoardState::CollectDragon => {
             if key == KeyCode::Esc {
                 *state = BoardState::View;
+                false
             } else {
                 let color = match key {
                     KeyCode::Char('g') => solitaire_base::card::DragonCard::Green,
                     KeyCode::Char('w') => solitaire_base::card::DragonCard::White,
                     KeyCode::Char('r') => solitaire_base::card::DragonCard::Red,
-                    _ => return,
+                    _ => return false,
                 };
                 if board.board.move_cards(solitaire_base::move_action::MoveAction::CollectDragon(color)) {
                     *state = BoardState::View;
+                    true
                 } else {
                     *info = Some("Cannot collect dragon".to_string());
+                    false
                 }
             }
         }
@@ -169,23 +232,25 @@ fn change_board_state(board: &mut Board, state: &mut BoardState, info: &mut Opti
             } else if let KeyCode::Char(c) = key {
                 if let Some(n) = c.to_digit(10) {
                     let index_th_stack_len = board.board.get(SolitaireSlot::Tray(*index)).count();
-                    if n == 0 || n as usize > index_th_stack_len { return; }
+                    if n == 0 || n as usize > index_th_stack_len { return false; }
                     for i in n as usize..index_th_stack_len {
                         if !board.board[SolitaireLocation::Tray(*index, i as u8)]
                             .can_stack_onto(&board.board[SolitaireLocation::Tray(*index, i as u8 - 1)]) {
                             *info = Some("Not a valid stack".into());
-                            return;
+                            return false;
                         }
                     }
                     *state = BoardState::Pickup(SolitaireLocation::Tray(*index, n as u8));
                 }
             }
+            false
         }
         BoardState::Pickup(location) => {
             if key == KeyCode::Esc {
                 *state = BoardState::View;
+                false
             } else {
-                let target_slot = if let Ok(slot) = key_to_slot(key) { slot } else { return; };
+                let target_slot = if let Ok(slot) = key_to_slot(key) { slot } else { return false; };
                 let source_card = match location {
                     SolitaireLocation::Spare(index) => board.board.get(SolitaireSlot::Spare(*index)).next(),
                     SolitaireLocation::Tray(x, y) => board.board.get(SolitaireSlot::Tray(*x)).nth((*y - 1) as usize),
@@ -193,7 +258,7 @@ fn change_board_state(board: &mut Board, state: &mut BoardState, info: &mut Opti
                 //Check if the move is valid
                 if !board.board.appendable(target_slot, source_card) {
                     *info = Some("Cannot stack onto that".to_string());
-                    return;
+                    return false;
                 }
                 //Move the card(delete from source and add to target)
                 let cards = match location {
@@ -209,12 +274,90 @@ fn change_board_state(board: &mut Board, state: &mut BoardState, info: &mut Opti
                 }
                 board.board.simplify();
                 *state = BoardState::View;
+                true
+            }
+        }
+        BoardState::Hint { .. } => {
+            // The hint is only a flashed overlay; any key dismisses it.
+            *state = BoardState::View;
+            false
+        }
+    }
+}
+
+/// Apply every currently-forced move until a full pass changes nothing: the
+/// flower and output auto-moves handled by `Board::simplify`, plus any dragon
+/// color whose four cards are all exposed onto a free spare. Returns `true` if
+/// the board changed at all, so the caller can record a history snapshot.
+fn autoplay(board: &mut Board) -> bool {
+    let start = solver::cards_left(&board.board);
+    loop {
+        let before = solver::cards_left(&board.board);
+        board.board.simplify();
+        let mut collected = false;
+        for color in [
+            solitaire_base::card::DragonCard::Green,
+            solitaire_base::card::DragonCard::White,
+            solitaire_base::card::DragonCard::Red,
+        ] {
+            if board.board.move_cards(solitaire_base::move_action::MoveAction::CollectDragon(color)) {
+                collected = true;
             }
         }
+        if !collected && solver::cards_left(&board.board) == before {
+            break;
+        }
     }
+    solver::cards_left(&board.board) != start
+}
+
+/// Undo/redo timeline of board positions. `states[cursor]` is the position on
+/// screen; `states[0]` is the deal. Because `Board` is `Clone`, each entry is a
+/// full snapshot — recording a fresh move truncates any redo tail.
+struct History {
+    states: Vec<Board>,
+    cursor: usize,
 }
 
-fn draw(frame: &mut Frame, board: &Board, board_state: &mut BoardState, info: &Option<String>) {
+impl History {
+    fn new(board: &Board) -> Self {
+        Self { states: vec![board.clone()], cursor: 0 }
+    }
+
+    /// Append the position reached by a successful move and advance onto it.
+    fn record(&mut self, board: &Board) {
+        self.states.truncate(self.cursor + 1);
+        self.states.push(board.clone());
+        self.cursor += 1;
+    }
+
+    /// Forget the timeline and start over from `board` (a fresh deal or load).
+    fn reset(&mut self, board: &Board) {
+        self.states = vec![board.clone()];
+        self.cursor = 0;
+    }
+
+    /// Step back one move, returning the position to restore.
+    fn undo(&mut self) -> Option<&Board> {
+        if self.cursor == 0 { return None; }
+        self.cursor -= 1;
+        Some(&self.states[self.cursor])
+    }
+
+    /// Step forward one move, returning the position to restore.
+    fn redo(&mut self) -> Option<&Board> {
+        if self.cursor + 1 >= self.states.len() { return None; }
+        self.cursor += 1;
+        Some(&self.states[self.cursor])
+    }
+
+    /// The current move number, counting the deal as move zero.
+    fn move_number(&self) -> usize {
+        self.cursor
+    }
+}
+
+fn draw(frame: &mut Frame, board: &Board, board_state: &mut BoardState, info: &Option<String>, move_number: usize) {
     let vertical_layout = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]);
     let [board_area, status_line] = vertical_layout.areas(Rect::new(0, 0, 25, 17));
     
@@ -224,7 +367,7 @@ fn draw(frame: &mut Frame, board: &Board, board_state: &mut BoardState, info: &O
         Line::from(info.clone()).on_red()
     } else {
         let cards = solitaire_base::index::ALL_SLOTS.iter().map(|slot| board.board.get(*slot).count()).sum::<usize>();
-        Line::from(if cards == 0 { Cow::Borrowed("Congratulations!") } else { Cow::Owned(format!("{cards}/40 cards left")) }).right_aligned().on_gray()
+        Line::from(if cards == 0 { Cow::Owned(format!("#{} · move {move_number} · Congratulations!", board.seed)) } else { Cow::Owned(format!("#{} · move {move_number} · {cards}/40 cards left", board.seed)) }).right_aligned().on_gray()
     }, status_line);
 }
 
@@ -243,15 +386,19 @@ async fn main() -> std::io::Result<()>{
         panic_fn(x);
     }));
 
-    let mut board = Board::new();
+    let mut board = match std::env::args().nth(1).and_then(|arg| arg.parse::<u64>().ok()) {
+        Some(seed) => Board::from_seed(seed),
+        None => Board::random(),
+    };
     let mut board_state = BoardState::View;
     let mut info = None::<String>;
+    let mut history = History::new(&board);
 
     loop {
         tokio::select! {
             _ = tokio::time::sleep(std::time::Duration::from_millis(100)) => {
                 terminal.draw(|frame| {
-                    draw(frame, &board, &mut board_state, &info);
+                    draw(frame, &board, &mut board_state, &info, history.move_number());
                 })?;
             }
             Some(Ok(event)) = event_stream.next().fuse() => {
@@ -260,12 +407,75 @@ async fn main() -> std::io::Result<()>{
                 if key == KeyCode::Char('q').into() {
                    break;
                 } else if key == KeyCode::Char('n').into() {
-                    board = Board::new();
-                } else {
-                    change_board_state(&mut board, &mut board_state, &mut info, key.code);
+                    board = Board::random();
+                    board_state = BoardState::View;
+                    history.reset(&board);
+                } else if key == KeyCode::Char('N').into() {
+                    board = Board::from_seed(board.seed);
+                    board_state = BoardState::View;
+                    history.reset(&board);
+                } else if matches!(board_state, BoardState::View) && key == KeyCode::Char('s').into() {
+                    match solver::solve(&board.board) {
+                        Some(moves) => {
+                            for mv in &moves {
+                                mv.apply(&mut board.board);
+                                board.board.simplify();
+                            }
+                            info = Some(format!("Solved in {} moves", moves.len()));
+                            history.record(&board);
+                        }
+                        None => info = Some("No solution found".to_string()),
+                    }
+                    board_state = BoardState::View;
+                } else if key == KeyCode::Char('u').into() {
+                    if let Some(prev) = history.undo() {
+                        board = prev.clone();
+                        board_state = BoardState::View;
+                        info = None;
+                    }
+                } else if key == KeyCode::Char('U').into()
+                    || key == KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL) {
+                    if let Some(next) = history.redo() {
+                        board = next.clone();
+                        board_state = BoardState::View;
+                        info = None;
+                    }
+                } else if matches!(board_state, BoardState::View)
+                    && (key == KeyCode::Char(' ').into() || key == KeyCode::Char('.').into()) {
+                    if autoplay(&mut board) {
+                        history.record(&board);
+                    }
+                    board_state = BoardState::View;
+                } else if matches!(board_state, BoardState::View) && key == KeyCode::Char('h').into() {
+                    match solver::hint(&board.board) {
+                        Some(mv @ solver::Move::Stack { from, depth, to }) => {
+                            info = Some(mv.to_string());
+                            board_state = BoardState::Hint { from, depth, to };
+                        }
+                        Some(mv @ solver::Move::Collect(_)) => info = Some(mv.to_string()),
+                        None => info = Some("no obvious move — try the solver".to_string()),
+                    }
+                } else if matches!(board_state, BoardState::View) && key == KeyCode::Char('w').into() {
+                    info = Some(match save::save(&board.board, save::DEFAULT_PATH) {
+                        Ok(()) => format!("Saved to {}", save::DEFAULT_PATH),
+                        Err(err) => format!("Save failed: {err}"),
+                    });
+                } else if matches!(board_state, BoardState::View)
+                    && (key == KeyCode::Char('o').into() || key == KeyCode::Char('l').into()) {
+                    match save::load(save::DEFAULT_PATH) {
+                        Ok(loaded) => {
+                            board.board = loaded;
+                            board_state = BoardState::View;
+                            history.reset(&board);
+                            info = Some(format!("Loaded from {}", save::DEFAULT_PATH));
+                        }
+                        Err(err) => info = Some(format!("Load failed: {err}")),
+                    }
+                } else if change_board_state(&mut board, &mut board_state, &mut info, key.code) {
+                    history.record(&board);
                 }
                 terminal.draw(|frame| {
-                    draw(frame, &board, &mut board_state, &info);
+                    draw(frame, &board, &mut board_state, &info, history.move_number());
                 })?;
             },
         }
@@ -0,0 +1,310 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use solitaire_base::card::{Card, DragonCard, NumberCard};
+use solitaire_base::index::{ALL_SLOTS, Slot};
+
+/// Default file a game is written to and resumed from.
+pub const DEFAULT_PATH: &str = "solitaire.json";
+
+/// Serializable mirror of a suit, so the on-disk format stays independent of
+/// whatever trait derivations the base crate happens to provide.
+#[derive(Serialize, Deserialize)]
+enum SuitRepr {
+    Bamboo,
+    Characters,
+    Coin,
+}
+
+/// Serializable mirror of a dragon color.
+#[derive(Serialize, Deserialize)]
+enum DragonRepr {
+    Green,
+    White,
+    Red,
+}
+
+/// Serializable mirror of a single card.
+#[derive(Serialize, Deserialize)]
+enum CardRepr {
+    Number(SuitRepr, u8),
+    Dragon(DragonRepr),
+    Flower,
+}
+
+/// Serializable mirror of one spare slot.
+#[derive(Serialize, Deserialize)]
+enum SpareRepr {
+    Empty,
+    /// A completed dragon foundation — the slot is permanently occupied.
+    Collected,
+    Card(CardRepr),
+}
+
+/// Serializable mirror of the `out()` foundation counts.
+#[derive(Serialize, Deserialize)]
+struct OutRepr {
+    bamboo: u8,
+    characters: u8,
+    coin: u8,
+}
+
+/// On-disk mirror of a game position. The three spares, eight tray columns,
+/// flower slot and `out()` counts together capture everything a player needs
+/// to resume. The mirror is built and rebuilt purely through the public
+/// `Board` API, so save/load never depends on the base crate implementing
+/// serde itself.
+#[derive(Serialize, Deserialize)]
+pub struct SaveState {
+    spares: [SpareRepr; 3],
+    trays: [Vec<CardRepr>; 8],
+    flower: bool,
+    out: OutRepr,
+}
+
+impl SaveState {
+    /// Read the live position into a mirror via the public getters.
+    fn capture(board: &solitaire_base::Board) -> Self {
+        let spares = std::array::from_fn(|i| {
+            let i = i as u8;
+            match board.get(Slot::Spare(i)).next() {
+                Some(card) => SpareRepr::Card(CardRepr::from(card)),
+                None if board.is_spare_collected(i) => SpareRepr::Collected,
+                None => SpareRepr::Empty,
+            }
+        });
+        let trays = std::array::from_fn(|j| {
+            board.get(Slot::Tray(j as u8)).map(CardRepr::from).collect()
+        });
+        let out = board.out();
+        Self {
+            spares,
+            trays,
+            flower: board.flower(),
+            out: OutRepr { bamboo: out.bamboo, characters: out.characters, coin: out.coin },
+        }
+    }
+
+    /// Rebuild a `Board` from the mirror, restoring every part of the position
+    /// explicitly rather than hoping `simplify` re-derives it.
+    fn restore(&self) -> solitaire_base::Board {
+        let mut board = solitaire_base::Board::new_random();
+        for slot in ALL_SLOTS.iter() {
+            while board.pop(*slot).is_some() {}
+        }
+
+        // 1. Re-create the foundations. Each already-output low card (and the
+        //    flower, if it was played) is laid alone on an otherwise-empty
+        //    column and sent home by `simplify`, which outputs whatever is the
+        //    lowest card in play. Driving `out()` this way reproduces the saved
+        //    counts exactly instead of leaving them at zero.
+        if self.flower {
+            board.push(Slot::Tray(0), Card::Flower);
+            board.simplify();
+        }
+        for (suit, count) in [
+            (NumberCard::Bamboo, self.out.bamboo),
+            (NumberCard::Characters, self.out.characters),
+            (NumberCard::Coin, self.out.coin),
+        ] {
+            for rank in 1..=count {
+                board.push(Slot::Tray(0), Card::Number(suit, rank));
+                board.simplify();
+            }
+        }
+
+        // 2. Place the in-play spare cards at their exact indices first, so the
+        //    dragon collections below can only fall into the saved `Collected`
+        //    indices (the engine fills the lowest free spare).
+        for (i, spare) in self.spares.iter().enumerate() {
+            if let SpareRepr::Card(card) = spare {
+                board.push(Slot::Spare(i as u8), Card::from(card));
+            }
+        }
+
+        // 3. Replay each fully-collected dragon color into a free spare.
+        if self.has_collected_spare() {
+            for color in [DragonCard::Green, DragonCard::White, DragonCard::Red] {
+                if self.color_present(color) {
+                    continue;
+                }
+                for tray in 0..4u8 {
+                    board.push(Slot::Tray(tray), Card::Dragon(color));
+                }
+                board.move_cards(solitaire_base::move_action::MoveAction::CollectDragon(color));
+            }
+        }
+
+        // 4. Deal the remaining in-play cards back into their columns. No final
+        //    `simplify`: a consistent save already applied every forced move,
+        //    so the layout is reinstated verbatim.
+        for (j, tray) in self.trays.iter().enumerate() {
+            for card in tray {
+                board.push(Slot::Tray(j as u8), Card::from(card));
+            }
+        }
+
+        board
+    }
+
+    /// Whether any dragon of `color` still sits in a spare or tray.
+    fn color_present(&self, color: DragonCard) -> bool {
+        let want = DragonRepr::from(color);
+        self.spares
+            .iter()
+            .any(|s| matches!(s, SpareRepr::Card(CardRepr::Dragon(d)) if d.same(&want)))
+            || self
+                .trays
+                .iter()
+                .flatten()
+                .any(|c| matches!(c, CardRepr::Dragon(d) if d.same(&want)))
+    }
+
+    /// Whether at least one spare records a completed dragon foundation.
+    fn has_collected_spare(&self) -> bool {
+        self.spares.iter().any(|s| matches!(s, SpareRepr::Collected))
+    }
+}
+
+impl DragonRepr {
+    fn same(&self, other: &DragonRepr) -> bool {
+        matches!(
+            (self, other),
+            (DragonRepr::Green, DragonRepr::Green)
+                | (DragonRepr::White, DragonRepr::White)
+                | (DragonRepr::Red, DragonRepr::Red)
+        )
+    }
+}
+
+impl From<&Card> for CardRepr {
+    fn from(card: &Card) -> Self {
+        match card {
+            Card::Number(suit, rank) => CardRepr::Number(SuitRepr::from(suit), *rank),
+            Card::Dragon(color) => CardRepr::Dragon(DragonRepr::from(*color)),
+            Card::Flower => CardRepr::Flower,
+        }
+    }
+}
+
+impl From<&CardRepr> for Card {
+    fn from(repr: &CardRepr) -> Self {
+        match repr {
+            CardRepr::Number(suit, rank) => Card::Number(NumberCard::from(suit), *rank),
+            CardRepr::Dragon(color) => Card::Dragon(DragonCard::from(color)),
+            CardRepr::Flower => Card::Flower,
+        }
+    }
+}
+
+impl From<&NumberCard> for SuitRepr {
+    fn from(suit: &NumberCard) -> Self {
+        match suit {
+            NumberCard::Bamboo => SuitRepr::Bamboo,
+            NumberCard::Characters => SuitRepr::Characters,
+            NumberCard::Coin => SuitRepr::Coin,
+        }
+    }
+}
+
+impl From<&SuitRepr> for NumberCard {
+    fn from(suit: &SuitRepr) -> Self {
+        match suit {
+            SuitRepr::Bamboo => NumberCard::Bamboo,
+            SuitRepr::Characters => NumberCard::Characters,
+            SuitRepr::Coin => NumberCard::Coin,
+        }
+    }
+}
+
+impl From<DragonCard> for DragonRepr {
+    fn from(color: DragonCard) -> Self {
+        match color {
+            DragonCard::Green => DragonRepr::Green,
+            DragonCard::White => DragonRepr::White,
+            DragonCard::Red => DragonRepr::Red,
+        }
+    }
+}
+
+impl From<&DragonRepr> for DragonCard {
+    fn from(color: &DragonRepr) -> Self {
+        match color {
+            DragonRepr::Green => DragonCard::Green,
+            DragonRepr::White => DragonCard::White,
+            DragonRepr::Red => DragonCard::Red,
+        }
+    }
+}
+
+/// Write the current position to `path` as JSON.
+pub fn save(board: &solitaire_base::Board, path: impl AsRef<Path>) -> Result<(), String> {
+    let state = SaveState::capture(board);
+    let json = serde_json::to_string(&state).map_err(|err| err.to_string())?;
+    std::fs::write(path, json).map_err(|err| err.to_string())
+}
+
+/// Read a saved position from `path`, returning a descriptive error for a
+/// missing or malformed file so the caller can surface it in the status line.
+pub fn load(path: impl AsRef<Path>) -> Result<solitaire_base::Board, String> {
+    let data = std::fs::read_to_string(path).map_err(|err| err.to_string())?;
+    let state: SaveState = serde_json::from_str(&data).map_err(|err| err.to_string())?;
+    Ok(state.restore())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The JSON mirror of a position, used to compare two boards for equality
+    /// through the public API alone.
+    fn snapshot(board: &solitaire_base::Board) -> String {
+        serde_json::to_string(&SaveState::capture(board)).unwrap()
+    }
+
+    /// Capturing then restoring a position must come back identical. The
+    /// position is a random deal after its forced moves and any dragon
+    /// collection it allows, so whether it holds `out()` foundations, a
+    /// collected dragon or the played flower depends on the deal.
+    #[test]
+    fn round_trip_preserves_mid_game_position() {
+        let mut board = solitaire_base::Board::new_random();
+        // Apply the forced moves and any dragon collection the deal allows.
+        board.simplify();
+        for color in [DragonCard::Green, DragonCard::White, DragonCard::Red] {
+            board.move_cards(solitaire_base::move_action::MoveAction::CollectDragon(color));
+        }
+        board.simplify();
+
+        let before = snapshot(&board);
+        let restored = SaveState::capture(&board).restore();
+        assert_eq!(before, snapshot(&restored));
+    }
+
+    #[test]
+    fn restore_keeps_collected_spare_index() {
+        // Spares [Card, Collected, Empty]: the green dragons are collected while
+        // white and red remain in play, so the replay must land on index 1 and
+        // leave the card at index 0 untouched.
+        let mut trays: [Vec<CardRepr>; 8] = Default::default();
+        for tray in trays.iter_mut().take(4) {
+            tray.push(CardRepr::Dragon(DragonRepr::White));
+        }
+        for tray in trays.iter_mut().skip(4) {
+            tray.push(CardRepr::Dragon(DragonRepr::Red));
+        }
+        let state = SaveState {
+            spares: [
+                SpareRepr::Card(CardRepr::Number(SuitRepr::Bamboo, 9)),
+                SpareRepr::Collected,
+                SpareRepr::Empty,
+            ],
+            trays,
+            flower: false,
+            out: OutRepr { bamboo: 0, characters: 0, coin: 0 },
+        };
+        let restored = state.restore();
+        assert!(restored.get(Slot::Spare(0)).next().is_some());
+        assert!(restored.is_spare_collected(1));
+    }
+}
@@ -0,0 +1,297 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use solitaire_base::card::{Card, DragonCard};
+use solitaire_base::index::{ALL_SLOTS, Location, Slot};
+use solitaire_base::move_action::MoveAction;
+
+/// A single step the solver can take from a board position: either a card run
+/// moved from one slot onto another, or a dragon collection. The two cases
+/// mirror the only mutations `change_board_state` ever performs.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    /// Move the bottom `depth` cards of `from` onto `to`.
+    Stack { from: Slot, depth: u8, to: Slot },
+    /// Collect all four dragons of `color` into a free spare.
+    Collect(DragonCard),
+}
+
+/// Depth cap on the search so a hopeless deal cannot run forever; real wins
+/// are far shorter than this.
+const MAX_DEPTH: usize = 200;
+
+/// Try to solve `board`. Returns the sequence of [`Move`]s that clears every
+/// card, or `None` if no line wins within [`MAX_DEPTH`] plies.
+pub fn solve(board: &solitaire_base::Board) -> Option<Vec<Move>> {
+    let mut start = board.clone();
+    start.simplify();
+    let mut visited = HashSet::new();
+    let mut path = Vec::new();
+    if dfs(&start, &mut visited, &mut path, 0) {
+        Some(path)
+    } else {
+        None
+    }
+}
+
+/// Ask for the single best next move from `board` using a shallow heuristic
+/// (no full solve). Prefers a move that outputs a card, then a legal dragon
+/// collection, then a tray move that empties a column. Returns `None` when
+/// nothing obvious scores.
+pub fn hint(board: &solitaire_base::Board) -> Option<Move> {
+    let moves = legal_moves(board);
+
+    // Score candidates in strict tiers so the request's priority holds:
+    // sending a card to `out()` always beats a dragon collection, which beats
+    // a tray move that merely empties a column. A move only counts as an output
+    // when it clears cards *without* being the collection itself.
+    let mut best: Option<(Move, usize)> = None;
+    for mv in moves {
+        let mut next = board.clone();
+        if !mv.apply(&mut next) {
+            continue;
+        }
+        next.simplify();
+        let cleared = cards_left(board).saturating_sub(cards_left(&next));
+        let score = match mv {
+            _ if matches!(mv, Move::Stack { .. }) && cleared > 0 => 3,
+            Move::Collect(_) => 2,
+            Move::Stack { from: Slot::Tray(_), depth, .. } if empties_column(board, mv, depth) => 1,
+            _ => 0,
+        };
+        if score == 0 {
+            continue;
+        }
+        if best.map_or(true, |(_, s)| score > s) {
+            best = Some((mv, score));
+        }
+    }
+    best.map(|(mv, _)| mv)
+}
+
+/// Whether moving `depth` cards empties the source tray column.
+fn empties_column(board: &solitaire_base::Board, mv: Move, depth: u8) -> bool {
+    if let Move::Stack { from: Slot::Tray(j), .. } = mv {
+        board.get(Slot::Tray(j)).count() == depth as usize
+    } else {
+        false
+    }
+}
+
+fn dfs(
+    board: &solitaire_base::Board,
+    visited: &mut HashSet<String>,
+    path: &mut Vec<Move>,
+    depth: usize,
+) -> bool {
+    if cards_left(board) == 0 {
+        return true;
+    }
+    if depth >= MAX_DEPTH {
+        return false;
+    }
+    if !visited.insert(canonical(board)) {
+        return false;
+    }
+    for mv in legal_moves(board) {
+        let mut next = board.clone();
+        if !mv.apply(&mut next) {
+            continue;
+        }
+        next.simplify();
+        path.push(mv);
+        if dfs(&next, visited, path, depth + 1) {
+            return true;
+        }
+        path.pop();
+    }
+    false
+}
+
+/// Enumerate every legal move from `board`: each movable tray run and each
+/// spare card onto every valid target, plus any currently legal dragon
+/// collection. Forced auto-moves are left to `Board::simplify`.
+fn legal_moves(board: &solitaire_base::Board) -> Vec<Move> {
+    let mut moves = Vec::new();
+
+    for i in 0..3 {
+        if let Some(&source) = board.get(Slot::Spare(i)).next() {
+            for to in ALL_SLOTS.iter().copied() {
+                if to == Slot::Spare(i) {
+                    continue;
+                }
+                if board.appendable(to, &source) {
+                    moves.push(Move::Stack { from: Slot::Spare(i), depth: 1, to });
+                }
+            }
+        }
+    }
+
+    for j in 0..8 {
+        let len = board.get(Slot::Tray(j)).count();
+        for n in 1..=len {
+            if !valid_run(board, j, n, len) {
+                continue;
+            }
+            let Some(&source) = board.get(Slot::Tray(j)).nth(n - 1) else {
+                continue;
+            };
+            let depth = (len - (n - 1)) as u8;
+            for to in ALL_SLOTS.iter().copied() {
+                if to == Slot::Tray(j) {
+                    continue;
+                }
+                if board.appendable(to, &source) {
+                    moves.push(Move::Stack { from: Slot::Tray(j), depth, to });
+                }
+            }
+        }
+    }
+
+    for color in [DragonCard::Green, DragonCard::White, DragonCard::Red] {
+        let mut probe = board.clone();
+        if probe.move_cards(MoveAction::CollectDragon(color)) {
+            moves.push(Move::Collect(color));
+        }
+    }
+
+    moves
+}
+
+/// Whether the run of tray column `j` starting at depth `n - 1` (so cards
+/// `n..len`) is a contiguous stack, i.e. each card stacks onto the one above.
+fn valid_run(board: &solitaire_base::Board, j: u8, n: usize, len: usize) -> bool {
+    (n..len).all(|i| {
+        board[Location::Tray(j, i as u8)].can_stack_onto(&board[Location::Tray(j, i as u8 - 1)])
+    })
+}
+
+impl Move {
+    /// Apply this move to `board`, mirroring `change_board_state`. Returns
+    /// `false` if the move turned out to be illegal.
+    pub fn apply(&self, board: &mut solitaire_base::Board) -> bool {
+        match self {
+            Move::Stack { from, depth, to } => {
+                let source = match from {
+                    Slot::Spare(i) => board.get(Slot::Spare(*i)).next().copied(),
+                    Slot::Tray(j) => {
+                        let len = board.get(Slot::Tray(*j)).count();
+                        match len.checked_sub(*depth as usize) {
+                            Some(top) => board.get(Slot::Tray(*j)).nth(top).copied(),
+                            None => None,
+                        }
+                    }
+                };
+                let Some(source) = source else {
+                    return false;
+                };
+                if !board.appendable(*to, &source) {
+                    return false;
+                }
+                let cards: Vec<Card> = match from {
+                    Slot::Spare(i) => vec![board.pop(Slot::Spare(*i)).unwrap()],
+                    Slot::Tray(j) => (0..*depth)
+                        .map(|_| board.pop(Slot::Tray(*j)).unwrap())
+                        .collect::<Vec<_>>()
+                        .into_iter()
+                        .rev()
+                        .collect(),
+                };
+                for card in cards {
+                    board.push(*to, card);
+                }
+                true
+            }
+            Move::Collect(color) => board.move_cards(MoveAction::CollectDragon(*color)),
+        }
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Move::Stack { from, depth, to } => {
+                write!(f, "move {depth} from {} onto {}", slot_name(*from), slot_name(*to))
+            }
+            Move::Collect(color) => write!(f, "collect {color:?} dragons"),
+        }
+    }
+}
+
+/// The key a player would press to address `slot`, matching `key_to_slot`.
+fn slot_name(slot: Slot) -> String {
+    match slot {
+        Slot::Spare(i) => ((b'a' + i) as char).to_string(),
+        Slot::Tray(j) => (j + 1).to_string(),
+    }
+}
+
+/// Total number of cards still sitting in spares and trays.
+pub fn cards_left(board: &solitaire_base::Board) -> usize {
+    ALL_SLOTS.iter().map(|slot| board.get(*slot).count()).sum()
+}
+
+/// Canonical key for the visited-set: the sorted multiset of spare contents
+/// and tray columns plus flower/out progress, so equivalent arrangements that
+/// differ only in slot order collapse to one state.
+fn canonical(board: &solitaire_base::Board) -> String {
+    let mut spares: Vec<String> = (0..3).map(|i| spare_key(board, i)).collect();
+    spares.sort();
+    let mut trays: Vec<String> = (0..8).map(|j| column_key(board, Slot::Tray(j))).collect();
+    trays.sort();
+    let out = board.out();
+    format!(
+        "{}#{}#F{}#{}.{}.{}",
+        spares.join(","),
+        trays.join(","),
+        u8::from(board.flower()),
+        out.bamboo,
+        out.characters,
+        out.coin,
+    )
+}
+
+fn spare_key(board: &solitaire_base::Board, i: u8) -> String {
+    match board.get(Slot::Spare(i)).next() {
+        Some(card) => format!("{card}"),
+        None if board.is_spare_collected(i) => "CO".to_string(),
+        None => String::new(),
+    }
+}
+
+fn column_key(board: &solitaire_base::Board, slot: Slot) -> String {
+    board.get(slot).map(|card| format!("{card}")).collect::<Vec<_>>().join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Strip every card off a deal to reach the won position.
+    fn emptied() -> solitaire_base::Board {
+        let mut board = solitaire_base::Board::new_random();
+        for slot in ALL_SLOTS.iter() {
+            while board.pop(*slot).is_some() {}
+        }
+        board
+    }
+
+    #[test]
+    fn won_board_solves_with_no_moves() {
+        let board = emptied();
+        assert_eq!(cards_left(&board), 0);
+        assert!(solve(&board) == Some(Vec::new()));
+    }
+
+    #[test]
+    fn emptied_board_has_no_legal_moves() {
+        assert!(legal_moves(&emptied()).is_empty());
+    }
+
+    #[test]
+    fn canonical_is_stable_across_clone() {
+        let board = solitaire_base::Board::new_random();
+        assert_eq!(canonical(&board), canonical(&board.clone()));
+        assert_eq!(cards_left(&board), 40);
+    }
+}